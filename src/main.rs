@@ -1,9 +1,9 @@
 use std::f32::consts::PI;
 use std::thread::sleep;
 use std::time::Duration;
-use std::{char, fmt, io};
+use std::{char, fmt};
 use std::fs::File;
-use std::io::{stdout, Read, Write};
+use std::io::{stdout, Write};
 
 //fn at(lat: i16, long: i16, map: String, w: i16, h: i16) {
 //    let y = ( lat % 181 ) - 90;
@@ -17,26 +17,59 @@ use std::io::{stdout, Read, Write};
 //}
 
 struct Frustum {
-    _fov:  f32,
-    _ar:   f32,
+    fov:   f32,
+    ar:    f32,
     _near: f32,
     _far:  f32,
 }
 
+// An orbiting look-at camera. `azimuth`/`elevation`/`distance` place the `eye`
+// on a sphere around `target`; everything else is derived on demand.
 struct Camera {
-    _frustum:   Frustum,
-    _azimuth:   f32,
-    _elevation: f32,
-    _distance:  f32,
+    frustum:   Frustum,
+    azimuth:   f32,
+    elevation: f32,
+    distance:  f32,
+    target:    Vec3,
+    up:        Vec3,
 }
 
-struct Coords {
-    lat:  f32,
-    long: f32,
-}
-impl fmt::Display for Coords {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.lat, self.long)
+impl Camera {
+    // Where the eye sits for the current orbit angles. `azimuth` sweeps around
+    // the target, `elevation` lifts above the horizon.
+    fn eye(&self) -> Vec3 {
+        self.target
+            + Vec3 {
+                x: self.distance * self.elevation.cos() * self.azimuth.sin(),
+                y: self.distance * self.elevation.sin(),
+                z: self.distance * self.elevation.cos() * self.azimuth.cos(),
+            }
+    }
+
+    // The usual right-handed look-at basis: `w` points back towards the eye,
+    // `u` is right, `v` is up.
+    fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> (Vec3, Vec3, Vec3) {
+        let w = (eye - target).normalized();
+        let u = up.cross(w).normalized();
+        let v = w.cross(u);
+        (u, v, w)
+    }
+
+    // Ray through pixel `(x, y)` of a `(width, height)` image. Screen coords are
+    // centred on the pixel and scaled by the field of view and aspect ratio.
+    fn ray(&self, x: f32, y: f32, width: f32, height: f32) -> Ray {
+        let eye = self.eye();
+        let (u, v, w) = Self::look_at(eye, self.target, self.up);
+
+        let half = (self.frustum.fov / 2.).tan();
+        // [0, w) -> [-1, 1), flipping y so row 0 is the top of the image.
+        let su = (2. * (x + 0.5) / width - 1.) * half * self.frustum.ar;
+        let sv = (1. - 2. * (y + 0.5) / height) * half;
+
+        Ray {
+            origin: eye,
+            direction: u * su + v * sv - w,
+        }
     }
 }
 
@@ -57,22 +90,6 @@ impl Vec3 {
         Vec3 { x, y, z }
     }
 
-    fn add(self, other: Vec3) -> Vec3 {
-        Vec3 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
-    }
-
-    fn sub(self, other: Vec3) -> Vec3 {
-        Vec3 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
-    }
-
     fn scale(self, scalar: f32) -> Vec3 {
         Vec3 {
             x: self.x * scalar,
@@ -92,10 +109,20 @@ impl Vec3 {
             z: self.x * other.y - self.y * other.x,
         }
     }
+
+    fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    fn normalized(self) -> Vec3 {
+        self.scale(1. / self.length())
+    }
 }
 
 use std::ops::{Add, Sub, Mul};
 
+use rayon::prelude::*;
+
 impl Add for Vec3 {
     type Output = Vec3;
 
@@ -132,225 +159,398 @@ impl Mul<f32> for Vec3 {
     }
 }
 
-//// The scene consists of a single sphere located around (0, 0), of radius 1.
-//fn perspective(_camera: &Camera) -> Option<Coords> {
-//    char::from_u32(0x2800 + random::<u8>() as u32).unwrap();
-//    return Some(Coords { lat: 0., long: 0. })
-//}
+// A ray is a point `origin` and a `direction` we march along for some `t >= 0`.
+// Every point on it is `origin + direction * t`.
+#[derive(Debug, Copy, Clone)]
+struct Ray {
+    origin:    Vec3,
+    direction: Vec3,
+}
+impl Ray {
+    fn at(self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+}
 
-// 
-// 
-// 
-// x^2 + y^2 + z^2 = 1
-// x^2 = 1 - y^2 - z^2
-// x = ±sqrt(1 - y^2 - z^2)
-//
-//
-// x0 + t*a = ±sqrt(1 - (y0 + t*b)^2 - (z0 + t*c)^2)
-//
-//
-// USE THIS ONE!!!!
-// (x0 + t*a)^2 + (y0 + t*b)^2 + (z0 + t*c)^2 = 1
-//
-// let's fix x0 and y0 to 0
-// (t*a)^2 + (t*b)^2 + (z0 + t*c)^2 = 1
-// t^2 * a^2 + t^2 * b^2 + (z0 + t * c)^2 = 1
-// t^2 (a^2 + b^2) + z0^2 + 2 * z0 * t * c + t^2*c^2 = 1
-// t^2 (a^2 + b^2 + c^2) + (2 * z0 * c) * t + (z0^2 - 1) = 0
-//
-// A = a^2 + b^2 + c^2
-// B = 2 * z0 * c
-// C = z0^2 - 1
-//
-// t = ( -B ± sqrt(B^2 - 4AC) ) / 2A
-// 
-// if (B^2 - 4AC) < 0, no solutions
-// if (B^2 - 4AC) = 0, one solution
-// if (B^2 - 4AC) > 0, two solutions
-// 
-// t = ( -(2 * z0 * c) ± sqrt((2 * z0 * c)^2 - 4*(a^2 + b^2 + c^2)*(z0^2 - 1)) ) / ( 2 * ( a^2 + b^2 + c^2 ) )
-// t = ( -(2 * z0 * c) ± sqrt(4[(z0 * c)^2 - (a^2 + b^2 + c^2)*(z0^2 - 1)]) ) / ( 2 * ( a^2 + b^2 + c^2 ) )
-// t = ( -2 * (z0 * c) ± 2 * sqrt((z0 * c)^2 - (a^2 + b^2 + c^2)*(z0^2 - 1)) ) / ( 2 * ( a^2 + b^2 + c^2 ) )
-//
-// t = ( -z0 * c ± sqrt((z0 * c)^2 - (a^2 + b^2 + c^2)*(z0^2 - 1)) ) / ( a^2 + b^2 + c^2 )
-//
-// 
-// Basically, if there exists a `t` that works, you're on the line.
-// (x-x0) / a = (y-y0) / b = (z-z0) / c
-//
-//
-//
-//
-// (x-x0) / a = (y-y0) / b = (z-z0) / c
-//
-fn isect(Vec3 { z: z0, .. }: Vec3, Vec3 { x: a, y: b, z: c }: Vec3) -> Option<Vec3> {
-    // Equation of a sphere at (0, 0, 0) of radius 1
-    // (x-x0)^2 + (y-y0)^2 + (z-z0)^2 = r^2
-    // Let's fix x0, y0, z0 = 0, and r = 1
-    //
-    // x^2 + y^2 + z^2 = 1
-    //
-    // Equation for a line going through (x, y, z) and towards (a, b, c)
-    // (x, y, z) = (x0, y0, z0) + t(a, b, c)
-    // t is any real value
-    //
-    // x = x0 + t * a
-    // y = y0 + t * b
-    // z = z0 + t * b
-    //
-    // Let's fix x0, y0 = 0
-    // (t*a)^2 + (t*b)^2 + (z0 + t*c)^2 = 1
-    // t^2 (a^2 + b^2 + c^2) + (2 * z0 * c) * t + (z0^2 - 1) = 0
-    // ^^^ there's our quadratic equation to solve
-    let A = a.powi(2)  + b.powi(2)  + c.powi(2) ;
-    let B = 2.  * z0 * c;
-    let C = z0.powi(2)  - 1. ;
-    // Just get the lowest value of t, the nearest to the camera
-    let t = if B.powi(2) - 4. * A * C < 0. { None }
-            else                           { Some( ( -B - (B.powi(2)  - 4. * A * C).sqrt() ) / ( 2. * A ) )};
-
-    //if t.is_some() {
-    //    println!("intersect: {}", Vec3 { x: a, y: b, z: c});
-    //} else {
-    //    println!("doesn't intersect: {}", Vec3 { x: a, y: b, z: c})
-    //}
+// What an intersection hands back: the parameter `t` along the ray, the `point`
+// in space and the outward surface `normal` there.
+#[derive(Debug, Copy, Clone)]
+struct Hit {
+    t:      f32,
+    point:  Vec3,
+    normal: Vec3,
+}
 
-    t.map(|tt| Vec3 {x: 0., y: 0., z: z0} + Vec3{ x: a, y: b, z: c} * tt)
+// Anything the ray can bump into. `hit` only reports intersections whose `t`
+// lands inside `[t_min, t_max]`, so callers can clip near/far and keep the
+// nearest so far.
+// `Send + Sync` so a `&Scene` can be shared across rayon worker threads while a
+// frame renders.
+trait Hittable: Send + Sync {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit>;
 }
 
-fn toGeometric(Vec3 { x, y, z }: Vec3) -> Coords {
-    //println!("toGeometric: {}", z);
-    fn angle(x: f32, y: f32, dot_product: f32) -> f32 {
-        //let magnitude_a = 1.0; // Magnitude of (0, -1) is 1
-        let magnitude_b = (x * x + y * y).sqrt();
-        ( dot_product / magnitude_b ).acos()
-    }
+struct Sphere {
+    center: Vec3,
+    radius: f32,
+}
+
+impl Hittable for Sphere {
+    // Plug the ray `o + t*d` into `|p - center|^2 = r^2` and you get a quadratic
+    // in `t`. Using `oc = o - center` and the half-`b` form to drop the factors
+    // of 2:
+    //   a    = d·d
+    //   b    = oc·d        (this is B/2)
+    //   c    = oc·oc - r^2
+    //   disc = b^2 - a*c   (this is (B^2 - 4AC) / 4)
+    // so the roots are `(-b ± sqrt(disc)) / a`.
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        let oc = ray.origin - self.center;
+        let a = ray.direction.dot(ray.direction);
+        let b = oc.dot(ray.direction);
+        let c = oc.dot(oc) - self.radius.powi(2);
+        let disc = b.powi(2) - a * c;
+        if disc < 0. {
+            return None;
+        }
 
-    // dot_product is -y for (-1, 0) dot (y, z), -z for (0, -1) dot (x, z)
-    Coords {
-        // lat is the angle between (-1, 0) and (y, z) on the yz plane
-        lat: angle(y, z, -y),
-        // long is the angle between (0, -1) and (x, z) on the xz plane
-        long: angle(x, z, -x),
+        // Nearest root first, fall back to the far one if it's behind `t_min`.
+        let sqrtd = disc.sqrt();
+        let mut t = (-b - sqrtd) / a;
+        if t < t_min || t > t_max {
+            t = (-b + sqrtd) / a;
+            if t < t_min || t > t_max {
+                return None;
+            }
+        }
+
+        let point = ray.at(t);
+        Some(Hit {
+            t,
+            point,
+            normal: (point - self.center).scale(1. / self.radius),
+        })
     }
 }
 
-fn texture3(coords: Coords, rot: f32, map: &String) -> char {
-    //println!("texture2: {}", coords);
+struct Plane {
+    point:  Vec3,
+    normal: Vec3,
+}
 
-    let long = ( ( coords.long + rot ) + ( PI * 2. ) ) % ( PI * 2. );
-    //let long = coords.long;
-    //let lat = ( coords.lat + rot ) % ( PI );
-    let lat = coords.lat;
+impl Hittable for Plane {
+    // `(p - point)·normal = 0`; substitute the ray and solve for `t`.
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        let denom = self.normal.dot(ray.direction);
+        // Ray parallel to the plane: no (useful) intersection.
+        if denom.abs() < 1e-6 {
+            return None;
+        }
 
-    let lines: Vec<&str> = map.split('\n').collect();
+        let t = (self.point - ray.origin).dot(self.normal) / denom;
+        if t < t_min || t > t_max {
+            return None;
+        }
 
-    let w = map[..map.find('\n').unwrap()].chars().count() as f32;
-    let h = map.chars().filter(|&c| c == '\n').count() as f32;
+        Some(Hit {
+            t,
+            point: ray.at(t),
+            // Always hand back a normal facing the incoming ray.
+            normal: if denom < 0. { self.normal } else { self.normal.scale(-1.) },
+        })
+    }
+}
 
-    let x = long * w / (2. * PI); // [0, 2 * PI[ -> [0, w[
-    let y = ( lat + 0.) * h / PI; // [- PI / 2, PI / 2[ -> [0, h[
-    
-    return lines[h as usize - y as usize].chars().nth(x as usize).unwrap_or(' ');
-
-    //if long % (PI / 6.) < 0.05 * PI / 12. {
-    //    char::from_u32(0x2800 + 0b1011_1000).unwrap()
-    //} else
-    if long % (PI / 6.) < PI / 12. {
-        //return '\u{28ff}';
-        return match y / h {
-            r if r < 0.2 => char::from_u32(0x2800 + 0b0000_0000).unwrap(),
-            r if r < 0.4 => char::from_u32(0x2800 + 0b0000_1001).unwrap(),
-            r if r < 0.6 => char::from_u32(0x2800 + 0b0001_1011).unwrap(),
-            r if r < 0.8 => char::from_u32(0x2800 + 0b0011_1111).unwrap(),
-            _            => char::from_u32(0x2800 + 0b1111_1111).unwrap(),
+// A world of objects. Its own `hit` keeps the closest intersection by shrinking
+// the `t_max` window as it walks the list.
+struct Scene(Vec<Box<dyn Hittable>>);
+
+impl Hittable for Scene {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        let mut closest = t_max;
+        let mut nearest = None;
+        for object in &self.0 {
+            if let Some(hit) = object.hit(ray, t_min, closest) {
+                closest = hit.t;
+                nearest = Some(hit);
+            }
         }
-    //} else if long % (PI / 6.) < PI / 12. {
-    //    char::from_u32(0x2800 + 0b0100_0111).unwrap()
-    } else {
-        '\u{2800}'
+        nearest
     }
 }
 
-fn texture2(coords: Coords, rot: f32) -> char {
-    //println!("texture2: {}", coords);
-    let long = coords.long + rot;
-    if long % (PI / 6.) < 0.05 * PI / 12. {
-        char::from_u32(0x2800 + 0b1011_1000).unwrap()
-    } else if long % (PI / 6.) < 0.95 * PI / 12. {
-        '\u{28ff}'
-    } else if long % (PI / 6.) < PI / 12. {
-        char::from_u32(0x2800 + 0b0100_0111).unwrap()
-    } else {
-        '\u{2800}'
+// A tiny xorshift PRNG. We only need it to scatter anti-aliasing samples, so
+// there's no point pulling in a dependency for it.
+struct Rng(u32);
+impl Rng {
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x
+    }
+
+    // Uniform in [0, 1).
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
     }
 }
 
-fn texture(coords: Option<Coords>) -> char {
-    match coords {
-        None => ' ',
-        Some(Coords { long, .. }) => 
-        if long % (PI / 6.) < 0.05 * PI / 12. {
-            char::from_u32(0x2800 + 0b1011_1000).unwrap()
-        } else if long % (PI / 6.) < 0.95 * PI / 12. {
-            '\u{28ff}'
-        } else if long % (PI / 6.) < PI / 12. {
-            char::from_u32(0x2800 + 0b0100_0111).unwrap()
-        } else {
-            '\u{2800}'
+impl Rng {
+    // A direction drawn uniformly over the hemisphere around `normal`. Reject
+    // points outside the unit ball for an even spread, then flip any sample that
+    // landed in the wrong hemisphere.
+    fn hemisphere(&mut self, normal: Vec3) -> Vec3 {
+        loop {
+            let p = Vec3::new(
+                self.next_f32() * 2. - 1.,
+                self.next_f32() * 2. - 1.,
+                self.next_f32() * 2. - 1.,
+            );
+            let len2 = p.dot(p);
+            if len2 > 1e-6 && len2 <= 1. {
+                let dir = p.normalized();
+                return if dir.dot(normal) < 0. { dir.scale(-1.) } else { dir };
+            }
         }
     }
 }
 
+// How exposed a surface point is: cast `k` occlusion rays over the hemisphere
+// around `normal` and return the fraction that escape without hitting anything
+// within `max_dist`. 1.0 is fully open sky, 0.0 is buried in a crevice. This is
+// what gives contact shadows where a sphere meets the ground plane.
+fn occlusion(scene: &Scene, point: Vec3, normal: Vec3, rng: &mut Rng, k: u32, max_dist: f32) -> f32 {
+    if k == 0 {
+        return 1.;
+    }
+    let mut escaped = 0u32;
+    for _ in 0..k {
+        let ray = Ray {
+            origin: point + normal.scale(1e-3),// nudge off the surface
+            direction: rng.hemisphere(normal),
+        };
+        if scene.hit(&ray, 1e-3, max_dist).is_none() {
+            escaped += 1;
+        }
+    }
+    escaped as f32 / k as f32
+}
+
+// How far an occlusion ray travels before we stop caring — larger values reach
+// for softer, wider-spread darkening.
+const AO_DISTANCE: f32 = 2.;
+
+// A single directional light plus a flat `ambient` floor so surfaces facing
+// away from it don't go pitch black.
+struct Light {
+    direction: Vec3,
+    ambient:   f32,
+}
+impl Light {
+    // Lambert's cosine law: brightness falls off with `normal·L`, clamped so
+    // back-faces contribute nothing beyond the ambient term.
+    fn intensity(&self, normal: Vec3) -> f32 {
+        let diffuse = normal.dot(self.direction.normalized()).max(0.);
+        (self.ambient + (1. - self.ambient) * diffuse).clamp(0., 1.)
+    }
+}
+
+// Map a shading `intensity` in [0, 1] onto a braille glyph by how many of its
+// eight dots are lit, walking a fill ramp from dark to solid. This turns the
+// U+2800 block into a 9-level grey ramp so the sphere reads as a shaded ball.
+fn shade(intensity: f32) -> char {
+    const RAMP: [u8; 9] = [
+        0b0000_0000,
+        0b0000_0100,
+        0b0000_0110,
+        0b0010_0110,
+        0b0010_0111,
+        0b0011_0111,
+        0b0011_1111,
+        0b0111_1111,
+        0b1111_1111,
+    ];
+    let i = (intensity.clamp(0., 1.) * (RAMP.len() - 1) as f32).round() as usize;
+    char::from_u32(0x2800 + RAMP[i] as u32).unwrap()
+}
+
+// Render a single frame at an arbitrary pixel resolution (no relation to the
+// terminal character grid) and return the bytes of a binary P6 PPM image.
+fn render_ppm(
+    width:   usize,
+    height:  usize,
+    scene:   &Scene,
+    camera:  &Camera,
+    light:   &Light,
+    samples: u32,
+    ao:      u32,
+) -> Vec<u8> {
+    let wf = width as f32;
+    let hf = height as f32;
+
+    // Rows render independently and in parallel, then concatenate in order after
+    // the PPM header.
+    let rows: Vec<Vec<u8>> = (0..height)
+        .into_par_iter()
+        .map(|y| {
+            let mut rng = Rng((0x1234_5678 ^ (y as u32).wrapping_mul(0x0100_0193)) | 1);
+            let mut row = Vec::with_capacity(width * 3);
+            for x in 0..width {
+                let mut acc = 0.;
+                for _ in 0..samples {
+                    let ray = camera.ray(
+                        x as f32 + rng.next_f32() - 0.5,
+                        y as f32 + rng.next_f32() - 0.5,
+                        wf,
+                        hf,
+                    );
+                    if let Some(hit) = scene.hit(&ray, 0., f32::INFINITY) {
+                        let open =
+                            occlusion(scene, hit.point, hit.normal, &mut rng, ao, AO_DISTANCE);
+                        acc += light.intensity(hit.normal) * open;
+                    }
+                }
+                // Shaded intensity drives a warm base tint; misses stay black.
+                let i = acc / samples as f32;
+                row.push((i * 235.) as u8);
+                row.push((i * 220.) as u8);
+                row.push((i * 190.) as u8);
+            }
+            row
+        })
+        .collect();
+
+    let mut out = Vec::new();
+    let _ = write!(out, "P6\n{} {}\n255\n", width, height);
+    for row in rows {
+        out.extend_from_slice(&row);
+    }
+    out
+}
+
 fn main() {
-    let Ok(mut file) = File::open("./data/s") else { return };
-
-    let mut contents = String::new();
-    let _ = file.read_to_string(&mut contents);
-
-    //println!("{}", contents);
-    let mut w: u8 = contents[..contents.find('\n').unwrap()].chars().count() as u8;
-
-    //let camera = Camera {
-    //    _frustum: Frustum {
-    //        _fov:  PI / 2.,
-    //        //_fov:  90.0_f32.to_radians(),
-    //        _ar:   16. / 9.,
-    //        _near: 0.1,
-    //        _far:  100.,
-    //    },
-    //    _azimuth:   0.,
-    //    _elevation: PI / 4.,
-    //    //_elevation: 45.0_f32.to_radians(),
-    //    _distance:  2.,
-    //};
-
-    w *= 1;
-    fn draw(w: u8, rot: f32, map: &String) -> Vec<u8> {
-        let mut buffer = Vec::new();
+    // Width of the braille grid, in character cells. The renderer is no longer
+    // tied to any on-disk texture, so this is just a size knob.
+    let w: u8 = 120;
+
+    // The world the rays trace against. `draw` no longer knows or cares how many
+    // objects live in here — drop more spheres/planes in and they just render.
+    let scene = Scene(vec![
+        Box::new(Sphere { center: Vec3::new(0., 0., 0.), radius: 1. }) as Box<dyn Hittable>,
+        // A ground plane for the globe to cast a contact shadow onto.
+        Box::new(Plane { point: Vec3::new(0., -1., 0.), normal: Vec3::new(0., 1., 0.) }),
+    ]);
+
+    fn draw(w: u8, scene: &Scene, camera: &Camera, light: &Light, samples: u32, ao: u32) -> Vec<u8> {
         let wf = w as f32;
-        for y in 0..w/4 {
-            let yf = y as f32;
-            for x in 0..w {
-                let xf = x as f32;
-                let pixel = isect(Vec3 {x: 0., y: 0., z: -1.5}, Vec3 {
-                    x: xf * 4./ wf - 2.,// [0, w[ -> [-2, +2[
-                    y: yf * -2. / (wf/4.) + 1.,// [0, w/2[ -> [1, -1[
-                    z: 1.0,
-                }).map(toGeometric).map_or(' ', |asdf| texture3(asdf, rot, map));
-                write!(buffer, "{}", pixel);
-            }
-            write!(buffer, "\n");
+        let hf = (w / 4) as f32;// braille cells are twice as tall as wide
+        // Each scanline is independent, so render them in parallel and stitch
+        // the rows back together in order. Nothing is shared mutably: every row
+        // owns its own jitter RNG, seeded from its index for reproducibility.
+        let rows: Vec<Vec<u8>> = (0..w / 4)
+            .into_par_iter()
+            .map(|y| {
+                let mut rng = Rng((0x9E37_79B9 ^ (y as u32).wrapping_mul(0x0100_0193)) | 1);
+                let mut row = Vec::new();
+                let yf = y as f32;
+                for x in 0..w {
+                    let xf = x as f32;
+                    // Cast `samples` jittered rays through the cell and average
+                    // both coverage and shading, so silhouettes soften.
+                    let mut hits = 0u32;
+                    let mut acc = 0.;
+                    for _ in 0..samples {
+                        let ray = camera.ray(
+                            xf + (rng.next_f32() - 0.5),
+                            yf + (rng.next_f32() - 0.5),
+                            wf,
+                            hf,
+                        );
+                        if let Some(hit) = scene.hit(&ray, 0., f32::INFINITY) {
+                            hits += 1;
+                            let open = occlusion(scene, hit.point, hit.normal, &mut rng, ao, AO_DISTANCE);
+                            acc += light.intensity(hit.normal) * open;
+                        }
+                    }
+                    let pixel = if hits == 0 {
+                        ' '
+                    } else {
+                        // Divide by `samples`, not `hits`: a cell only half on
+                        // the sphere renders at half intensity.
+                        shade(acc / samples as f32)
+                    };
+                    write!(row, "{}", pixel).unwrap();
+                }
+                writeln!(row).unwrap();
+                row
+            })
+            .collect();
+        rows.concat()
+    }
+
+    // The grid is `w` cells wide by `w/4` tall, and terminal cells are about
+    // twice as tall as they are wide. Fold both into the aspect ratio so the
+    // globe stays circular instead of squashing into an ellipse — this recovers
+    // the old x∈[-2,2] vs y∈[-1,1] mapping (a 2:1 horizontal stretch).
+    const CELL_ASPECT: f32 = 2.;// character height / width
+    let ar = (w as f32 / (w / 4) as f32) / CELL_ASPECT;
+
+    let mut camera = Camera {
+        frustum: Frustum {
+            fov:   PI / 2.,
+            ar,
+            _near: 0.1,
+            _far:  100.,
+        },
+        azimuth:   0.,
+        elevation: 0.,
+        distance:  2.5,
+        target:    Vec3::new(0., 0., 0.),
+        up:        Vec3::new(0., 1., 0.),
+    };
+
+    // Relight the scene by tweaking these: `direction` points towards the light,
+    // `ambient` lifts the shadow side off pure black.
+    let light = Light {
+        direction: Vec3::new(-0.5, 1., -0.5),
+        ambient:   0.1,
+    };
+
+    // Rays per character cell. Crank it up for smoother silhouettes.
+    let samples_per_pixel = 16;
+
+    // Occlusion rays per primary hit — the soft-shadow / ambient-occlusion
+    // quality knob. 0 disables it and falls back to plain Lambertian shading.
+    let occlusion_samples = 8;
+
+    // `--ppm [path]` renders one high-resolution frame to a PPM file and exits;
+    // otherwise we fall through to the live braille animation.
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|a| a == "--ppm") {
+        let path = args
+            .iter()
+            .skip_while(|a| *a != "--ppm")
+            .nth(1)
+            .cloned()
+            .unwrap_or_else(|| "out.ppm".to_string());
+        let pw = 1024;
+        let ph = (pw as f32 / camera.frustum.ar) as usize;
+        let image = render_ppm(pw, ph, &scene, &camera, &light, 64, occlusion_samples);
+        if let Ok(mut out) = File::create(&path) {
+            let _ = out.write_all(&image);
         }
-        buffer
+        return;
     }
 
-    let mut rot = 0.;
     loop {
-        let buf = draw(w, rot, &contents);
+        let buf = draw(w, &scene, &camera, &light, samples_per_pixel, occlusion_samples);
         let _ = stdout().write(&buf);
         let _ = stdout().flush();
-        rot += PI / 30.;
+        // Orbit around the globe rather than spinning its texture underfoot.
+        camera.azimuth += PI / 30.;
         sleep(Duration::from_millis(1000 / 60));
         print!("\r\x1B[{}A", buf.iter().filter(|&&c| c == b'\n').count());
     }